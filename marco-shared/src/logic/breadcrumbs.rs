@@ -0,0 +1,95 @@
+/// Render an accessible breadcrumb trail for a document path.
+///
+/// Each segment links to the cumulative path below `base_url`, so
+/// `["guide", "setup"]` with base `/docs` links to `/docs/guide` and
+/// `/docs/guide/setup`. The last item is the current page and is marked with
+/// `aria-current="page"`. Segment text is HTML-escaped and path characters
+/// outside the URL-safe set are percent-encoded. Returns an empty string when
+/// there are no segments.
+///
+/// # Example
+/// ```
+/// use marco_shared::logic::breadcrumbs::render_breadcrumbs;
+///
+/// let html = render_breadcrumbs(&["guide", "setup"], "/docs");
+/// assert!(html.starts_with("<nav aria-label=\"breadcrumb\"><ol>"));
+/// assert!(html.contains("<a href=\"/docs/guide/setup\" aria-current=\"page\">setup</a>"));
+/// ```
+pub fn render_breadcrumbs(path_segments: &[&str], base_url: &str) -> String {
+    if path_segments.is_empty() {
+        return String::new();
+    }
+
+    let mut href = base_url.trim_end_matches('/').to_string();
+    let mut html = String::from("<nav aria-label=\"breadcrumb\"><ol>");
+    for (i, segment) in path_segments.iter().enumerate() {
+        href.push('/');
+        href.push_str(&percent_encode_segment(segment));
+
+        let current = if i + 1 == path_segments.len() {
+            " aria-current=\"page\""
+        } else {
+            ""
+        };
+        html.push_str(&format!(
+            "<li><a href=\"{}\"{}>{}</a></li>",
+            escape_html(&href),
+            current,
+            escape_html(segment)
+        ));
+    }
+    html.push_str("</ol></nav>");
+    html
+}
+
+/// Percent-encode everything except unreserved URL characters
+fn percent_encode_segment(segment: &str) -> String {
+    let mut encoded = String::with_capacity(segment.len());
+    for byte in segment.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    encoded
+}
+
+/// Escape HTML special characters for text and attribute values
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::render_breadcrumbs;
+
+    #[test]
+    fn smoke_test_three_segments_link_cumulative_paths() {
+        let html = render_breadcrumbs(&["guide", "setup", "install"], "https://example.com/docs/");
+
+        assert_eq!(
+            html,
+            "<nav aria-label=\"breadcrumb\"><ol>\
+             <li><a href=\"https://example.com/docs/guide\">guide</a></li>\
+             <li><a href=\"https://example.com/docs/guide/setup\">setup</a></li>\
+             <li><a href=\"https://example.com/docs/guide/setup/install\" aria-current=\"page\">install</a></li>\
+             </ol></nav>"
+        );
+        assert_eq!(html.matches("<a href=").count(), 3);
+        assert_eq!(html.matches("aria-current").count(), 1);
+    }
+
+    #[test]
+    fn smoke_test_segments_are_escaped() {
+        let html = render_breadcrumbs(&["Q&A <notes>"], "");
+
+        assert!(html.contains("href=\"/Q%26A%20%3Cnotes%3E\""));
+        assert!(html.contains(">Q&amp;A &lt;notes&gt;</a>"));
+        assert_eq!(render_breadcrumbs(&[], "/docs"), "");
+    }
+}
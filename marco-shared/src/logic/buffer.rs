@@ -1,5 +1,6 @@
 use crate::logic::swanson::SettingsManager;
 use marco_core::logic::cache::{cached, global_cache};
+use std::borrow::Cow;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
@@ -25,6 +26,22 @@ pub struct DocumentBuffer {
     pub baseline_content: String,
     /// Display name for the document (filename or "Untitled.md")
     pub display_name: String,
+    /// Text normalization applied to content when saving (disabled by default)
+    normalize_options: NormalizeOptions,
+}
+
+/// Text normalization applied when a document is saved
+///
+/// All transforms are opt-in; the default leaves content untouched.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NormalizeOptions {
+    /// Remove trailing spaces and tabs from every line.
+    /// Lines inside fenced code blocks (also within block quotes and list
+    /// items) and indented code blocks are left untouched, and Markdown hard
+    /// line breaks (two or more trailing spaces before a non-blank line) are kept.
+    pub trim_trailing_whitespace: bool,
+    /// End the document with exactly one newline
+    pub ensure_final_newline: bool,
 }
 
 impl DocumentBuffer {
@@ -45,6 +62,7 @@ impl DocumentBuffer {
             is_modified: false,
             baseline_content: String::new(),
             display_name: "Untitled.md".to_string(),
+            normalize_options: NormalizeOptions::default(),
         }
     }
 
@@ -86,6 +104,7 @@ impl DocumentBuffer {
             is_modified: false,
             baseline_content: String::new(),
             display_name,
+            normalize_options: NormalizeOptions::default(),
         };
 
         log::info!(
@@ -125,9 +144,40 @@ impl DocumentBuffer {
         }
     }
 
+    /// Sets the text normalization applied by subsequent saves
+    ///
+    /// [`save_content`](Self::save_content) and
+    /// [`save_as_content`](Self::save_as_content) write the normalized text, which
+    /// also becomes the new `baseline_content`. Callers that keep their own copy
+    /// of the text (such as the editor) should update it from the baseline.
+    ///
+    /// # Example
+    /// ```
+    /// use marco_shared::logic::buffer::{DocumentBuffer, NormalizeOptions};
+    ///
+    /// let mut buffer = DocumentBuffer::new_untitled();
+    /// buffer.normalize_on_save(NormalizeOptions {
+    ///     trim_trailing_whitespace: true,
+    ///     ensure_final_newline: true,
+    /// });
+    /// ```
+    pub fn normalize_on_save(&mut self, opts: NormalizeOptions) {
+        if self.normalize_options != opts {
+            log::debug!(
+                "Save normalization for {:?}: trim_trailing_whitespace={}, ensure_final_newline={}",
+                self.file_path,
+                opts.trim_trailing_whitespace,
+                opts.ensure_final_newline
+            );
+        }
+        self.normalize_options = opts;
+    }
+
     /// Saves content to the file associated with this buffer
     ///
     /// Uses cached file operations and automatically invalidates the cache.
+    /// Content is normalized first if [`normalize_on_save`](Self::normalize_on_save)
+    /// was enabled; the written text becomes `baseline_content`.
     ///
     /// # Arguments
     /// * `content` - Text content to save
@@ -138,6 +188,7 @@ impl DocumentBuffer {
     ///
     /// # Side Effects
     /// - Sets `is_modified` to `false` on successful save
+    /// - Applies save normalization if enabled
     /// - Invalidates file cache entry
     ///
     /// # Example
@@ -152,6 +203,8 @@ impl DocumentBuffer {
     /// # }
     /// ```
     pub fn save_content(&mut self, content: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let content = normalize_text(content, &self.normalize_options);
+        let content = content.as_ref();
         match &self.file_path {
             Some(path) => {
                 // Create parent directories if they don't exist
@@ -205,6 +258,7 @@ impl DocumentBuffer {
     /// - Updates `display_name` to the new filename
     /// - Sets `is_modified` to `false` on successful save
     /// - Automatically appends `.md` extension if missing
+    /// - Applies save normalization if enabled
     /// - Invalidates cache entries
     ///
     /// # Example
@@ -224,6 +278,8 @@ impl DocumentBuffer {
         path: P,
         content: &str,
     ) -> Result<(), Box<dyn std::error::Error>> {
+        let content = normalize_text(content, &self.normalize_options);
+        let content = content.as_ref();
         let mut path = path.as_ref().to_path_buf();
 
         // Ensure the file has a .md extension
@@ -568,6 +624,269 @@ impl DocumentBuffer {
     }
 }
 
+/// Applies the given normalization to document text
+///
+/// Returns the input unchanged (borrowed) when no transform applies, so callers
+/// can cheaply detect whether saving would alter the editor content.
+///
+/// # Example
+/// ```
+/// use marco_shared::logic::buffer::{normalize_text, NormalizeOptions};
+///
+/// let opts = NormalizeOptions {
+///     trim_trailing_whitespace: true,
+///     ensure_final_newline: true,
+/// };
+/// assert_eq!(normalize_text("# Title \t\n\nText", &opts), "# Title\n\nText\n");
+/// ```
+pub fn normalize_text<'a>(content: &'a str, opts: &NormalizeOptions) -> Cow<'a, str> {
+    if !opts.trim_trailing_whitespace && !opts.ensure_final_newline {
+        return Cow::Borrowed(content);
+    }
+
+    let mut normalized = String::with_capacity(content.len() + 1);
+
+    if opts.trim_trailing_whitespace {
+        // Trailing whitespace inside code blocks is content, so those lines
+        // are copied verbatim. When unsure, a line is treated as code.
+        let mut fence: Option<Fence> = None;
+        let mut in_indented_code = false;
+        let mut prev_blank = true;
+
+        let mut lines = content.split_inclusive('\n').peekable();
+        while let Some(segment) = lines.next() {
+            let (line, ending) = split_line_ending(segment);
+            let blank = line.trim().is_empty();
+            let mut is_fence = false;
+
+            let verbatim = if let Some(open) = &fence {
+                is_fence = open.is_closed_by(line);
+                if is_fence {
+                    fence = None;
+                }
+                !is_fence
+            } else {
+                // Indented code cannot interrupt a paragraph, and blank lines
+                // inside it keep their indentation
+                if !blank {
+                    in_indented_code = is_indented(line) && (prev_blank || in_indented_code);
+                }
+                if !in_indented_code {
+                    fence = Fence::open(line);
+                    is_fence = fence.is_some();
+                }
+                in_indented_code
+            };
+            prev_blank = blank;
+
+            if verbatim {
+                normalized.push_str(segment);
+                continue;
+            }
+
+            let trimmed = line.trim_end_matches([' ', '\t']);
+
+            // Two or more trailing spaces followed by more paragraph text is a
+            // Markdown hard line break; trimming it would change the rendering.
+            let trailing = &line[trimmed.len()..];
+            let is_hard_break = !is_fence
+                && !trimmed.is_empty()
+                && trailing.len() >= 2
+                && trailing.bytes().all(|b| b == b' ')
+                && lines
+                    .peek()
+                    .is_some_and(|next| !split_line_ending(next).0.trim().is_empty());
+
+            normalized.push_str(if is_hard_break { line } else { trimmed });
+            normalized.push_str(ending);
+        }
+    } else {
+        normalized.push_str(content);
+    }
+
+    if opts.ensure_final_newline {
+        let body_len = normalized.trim_end_matches(['\n', '\r']).len();
+        // Leave empty documents empty rather than saving a lone newline
+        if body_len > 0 {
+            let newline = if content.contains("\r\n") {
+                "\r\n"
+            } else {
+                "\n"
+            };
+            normalized.truncate(body_len);
+            normalized.push_str(newline);
+        } else {
+            normalized.clear();
+        }
+    }
+
+    if normalized == content {
+        Cow::Borrowed(content)
+    } else {
+        Cow::Owned(normalized)
+    }
+}
+
+/// Replacement of the characters `start..end` with `text`
+///
+/// Offsets count characters, not bytes, matching GTK text buffer offsets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    /// Character offset where the replaced range starts
+    pub start: usize,
+    /// Character offset where the replaced range ends (exclusive)
+    pub end: usize,
+    /// Replacement text
+    pub text: String,
+}
+
+/// Computes the edits that turn `original` into `normalized`
+///
+/// `normalized` is expected to come from [`normalize_text`], which only trims
+/// line ends and adjusts the end of the document. Lines are compared one by
+/// one so each trimmed line becomes a small deletion; any remaining difference
+/// is replaced as one edit at the end. Edits are returned last-first, so
+/// applying them in order keeps the offsets of the remaining ones valid.
+///
+/// # Example
+/// ```
+/// use marco_shared::logic::buffer::{normalize_edits, TextEdit};
+///
+/// let edits = normalize_edits("a  \nb", "a\nb\n");
+/// assert_eq!(
+///     edits,
+///     vec![
+///         TextEdit { start: 5, end: 5, text: "\n".to_string() },
+///         TextEdit { start: 1, end: 3, text: String::new() },
+///     ]
+/// );
+/// ```
+pub fn normalize_edits(original: &str, normalized: &str) -> Vec<TextEdit> {
+    let mut edits = Vec::new();
+    let mut orig_lines = original.split_inclusive('\n').peekable();
+    let mut norm_lines = normalized.split_inclusive('\n').peekable();
+    let (mut orig_bytes, mut norm_bytes, mut orig_chars) = (0, 0, 0);
+
+    while let (Some(orig), Some(norm)) = (orig_lines.peek(), norm_lines.peek()) {
+        let (orig_line, orig_ending) = split_line_ending(orig);
+        let (norm_line, norm_ending) = split_line_ending(norm);
+        if orig != norm {
+            if orig_ending != norm_ending || !orig_line.starts_with(norm_line) {
+                break;
+            }
+            edits.push(TextEdit {
+                start: orig_chars + norm_line.chars().count(),
+                end: orig_chars + orig_line.chars().count(),
+                text: String::new(),
+            });
+        }
+        orig_bytes += orig.len();
+        norm_bytes += norm.len();
+        orig_chars += orig.chars().count();
+        orig_lines.next();
+        norm_lines.next();
+    }
+
+    let (orig_rest, norm_rest) = (&original[orig_bytes..], &normalized[norm_bytes..]);
+    if orig_rest != norm_rest {
+        let common = orig_rest
+            .chars()
+            .zip(norm_rest.chars())
+            .take_while(|(a, b)| a == b)
+            .count();
+        edits.push(TextEdit {
+            start: orig_chars + common,
+            end: orig_chars + orig_rest.chars().count(),
+            text: norm_rest.chars().skip(common).collect(),
+        });
+    }
+
+    edits.reverse();
+    edits
+}
+
+/// An open fenced code block
+struct Fence {
+    /// Fence character, '`' or '~'
+    marker: char,
+    /// Number of fence characters in the opening fence
+    len: usize,
+    /// Byte offset of the opening fence within its line
+    offset: usize,
+}
+
+impl Fence {
+    /// Returns the fence opened by `line`, if any
+    fn open(line: &str) -> Option<Self> {
+        let rest = strip_container_prefix(line);
+        let marker = rest.chars().next().filter(|c| *c == '`' || *c == '~')?;
+        let len = rest.chars().take_while(|c| *c == marker).count();
+        // Backtick fences cannot have backticks in their info string
+        if len < 3 || (marker == '`' && rest[len..].contains('`')) {
+            return None;
+        }
+        Some(Self {
+            marker,
+            len,
+            offset: line.len() - rest.len(),
+        })
+    }
+
+    /// Returns `true` if `line` closes this fence
+    ///
+    /// The closing fence may be indented up to three columns more than the
+    /// opening one; deeper lines are code content.
+    fn is_closed_by(&self, line: &str) -> bool {
+        let rest = strip_container_prefix(line);
+        let count = rest.chars().take_while(|c| *c == self.marker).count();
+        line.len() - rest.len() <= self.offset + 3
+            && count >= self.len
+            && rest[count..].trim().is_empty()
+    }
+}
+
+/// Strips indentation, block quote markers and list markers from the start
+/// of `line`, so fences nested in containers are recognised
+fn strip_container_prefix(line: &str) -> &str {
+    let mut rest = line.trim_start();
+    loop {
+        if let Some(inner) = rest.strip_prefix('>') {
+            rest = inner.trim_start();
+            continue;
+        }
+
+        let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        let marker_len = match rest.as_bytes().get(digits) {
+            Some(b'-' | b'*' | b'+') if digits == 0 => 1,
+            Some(b'.' | b')') if (1..=9).contains(&digits) => digits + 1,
+            _ => return rest,
+        };
+        match rest[marker_len..].strip_prefix([' ', '\t']) {
+            Some(inner) => rest = inner.trim_start(),
+            None => return rest,
+        }
+    }
+}
+
+/// Returns `true` if `line` is indented by at least four columns
+fn is_indented(line: &str) -> bool {
+    line.starts_with("    ") || line.starts_with('\t') || {
+        let indent = line.len() - line.trim_start_matches(' ').len();
+        indent < 4 && line[indent..].starts_with('\t')
+    }
+}
+
+/// Splits a line segment into its text and line ending ("\n", "\r\n" or "")
+fn split_line_ending(segment: &str) -> (&str, &str) {
+    if let Some(line) = segment.strip_suffix("\r\n") {
+        (line, "\r\n")
+    } else if let Some(line) = segment.strip_suffix('\n') {
+        (line, "\n")
+    } else {
+        (segment, "")
+    }
+}
+
 /// Recent files manager for tracking and persisting recently opened files
 ///
 /// This struct manages a list of recently opened files through the
@@ -784,4 +1103,219 @@ mod tests {
         assert_eq!(buffer.baseline_content, "New content");
         assert!(!buffer.is_modified);
     }
+
+    #[test]
+    fn smoke_test_normalize_text_disabled_by_default() {
+        let content = "# Title  \nno final newline\t";
+        let normalized = normalize_text(content, &NormalizeOptions::default());
+        assert!(matches!(normalized, Cow::Borrowed(_)));
+        assert_eq!(normalized, content);
+    }
+
+    #[test]
+    fn smoke_test_normalize_text_trims_and_adds_final_newline() {
+        let opts = NormalizeOptions {
+            trim_trailing_whitespace: true,
+            ensure_final_newline: true,
+        };
+
+        assert_eq!(
+            normalize_text("# Title \t\n\nSome text   \n\n- item\t", &opts),
+            "# Title\n\nSome text\n\n- item\n"
+        );
+        // Multiple trailing newlines collapse to one
+        assert_eq!(normalize_text("Text\n\n\n", &opts), "Text\n");
+        // CRLF documents keep their line endings
+        assert_eq!(
+            normalize_text("Line one  \r\n\r\nLine two ", &opts),
+            "Line one\r\n\r\nLine two\r\n"
+        );
+        // Empty documents stay empty
+        assert_eq!(normalize_text("\n\n", &opts), "");
+    }
+
+    #[test]
+    fn smoke_test_normalize_text_keeps_markdown_hard_breaks() {
+        let opts = NormalizeOptions {
+            trim_trailing_whitespace: true,
+            ensure_final_newline: false,
+        };
+
+        assert_eq!(
+            normalize_text("first line  \nsecond line  \n\nnext  ", &opts),
+            "first line  \nsecond line\n\nnext"
+        );
+    }
+
+    #[test]
+    fn smoke_test_normalize_text_keeps_code_blocks_verbatim() {
+        let opts = NormalizeOptions {
+            trim_trailing_whitespace: true,
+            ensure_final_newline: false,
+        };
+
+        // Fenced code, including a longer closing fence and tilde fences
+        assert_eq!(
+            normalize_text(
+                "Text \n````rust  \nlet a = 1;  \n```\n  \n````  \nAfter \n",
+                &opts
+            ),
+            "Text\n````rust\nlet a = 1;  \n```\n  \n````\nAfter\n"
+        );
+        assert_eq!(
+            normalize_text("~~~\ncode\t\n~~~\n", &opts),
+            "~~~\ncode\t\n~~~\n"
+        );
+
+        // Indented code after a blank line, but not a paragraph continuation
+        assert_eq!(
+            normalize_text("Para \n\n    code  \n\n    more\t\nEnd \n", &opts),
+            "Para\n\n    code  \n\n    more\t\nEnd\n"
+        );
+        assert_eq!(
+            normalize_text("Para\n    continued \n", &opts),
+            "Para\n    continued\n"
+        );
+    }
+
+    #[test]
+    fn smoke_test_normalize_text_keeps_nested_code_blocks_verbatim() {
+        let opts = NormalizeOptions {
+            trim_trailing_whitespace: true,
+            ensure_final_newline: false,
+        };
+
+        // Fence inside a block quote
+        assert_eq!(
+            normalize_text("> ```\n> keep\t\n> ```\n> quote \n", &opts),
+            "> ```\n> keep\t\n> ```\n> quote\n"
+        );
+
+        // Fence inside a nested list item
+        let nested = "1. Item\n   - nested\n     ```\n     x = 1 \t\n     ```\n";
+        assert_eq!(normalize_text(nested, &opts), nested);
+
+        // Fence on the list marker line itself
+        assert_eq!(
+            normalize_text("- ```\n  code  \n  ```\n- item \n", &opts),
+            "- ```\n  code  \n  ```\n- item\n"
+        );
+
+        // A deeper fence line inside a fence is content, not a closing fence
+        let inner = "```\n    ```\nstill code \n```\n";
+        assert_eq!(normalize_text(inner, &opts), inner);
+    }
+
+    fn apply_edits(text: &str, edits: &[TextEdit]) -> String {
+        let mut chars: Vec<char> = text.chars().collect();
+        for edit in edits {
+            chars.splice(edit.start..edit.end, edit.text.chars());
+        }
+        chars.into_iter().collect()
+    }
+
+    #[test]
+    fn smoke_test_normalize_edits_only_touch_changed_ranges() {
+        let opts = NormalizeOptions {
+            trim_trailing_whitespace: true,
+            ensure_final_newline: true,
+        };
+
+        for original in [
+            "# Title \t\n\nSome text   \n\n- item\t",
+            "Text\n\n\n",
+            "Line one  \r\n\r\nLine two ",
+            "Größe  \n```\nkeep \n```\nÜber \n\n",
+            "clean\n",
+            "\n\n",
+        ] {
+            let normalized = normalize_text(original, &opts);
+            let edits = normalize_edits(original, &normalized);
+            assert_eq!(apply_edits(original, &edits), normalized, "{original:?}");
+        }
+
+        // Only the trailing whitespace goes, the rest of each line is kept
+        assert_eq!(
+            normalize_edits("ab  \ncd\t\n", "ab\ncd\n"),
+            vec![
+                TextEdit {
+                    start: 7,
+                    end: 8,
+                    text: String::new()
+                },
+                TextEdit {
+                    start: 2,
+                    end: 4,
+                    text: String::new()
+                },
+            ]
+        );
+        assert!(normalize_edits("same\n", "same\n").is_empty());
+    }
+
+    #[test]
+    fn smoke_test_save_applies_normalization() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("normalize.md");
+
+        let mut buffer = DocumentBuffer::new_untitled();
+        buffer.normalize_on_save(NormalizeOptions {
+            trim_trailing_whitespace: true,
+            ensure_final_newline: true,
+        });
+        buffer
+            .save_as_content(&file_path, "# Title   \n\nBody text\t")
+            .unwrap();
+
+        let saved = fs::read_to_string(&file_path).unwrap();
+        assert_eq!(saved, "# Title\n\nBody text\n");
+        assert_eq!(buffer.baseline_content, saved);
+        assert!(!buffer.is_modified);
+    }
+
+    #[test]
+    fn smoke_test_save_dirty_content_leaves_editor_clean() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("dirty.md");
+        fs::write(&file_path, "# Title\n").unwrap();
+
+        let mut buffer = DocumentBuffer::new_from_file(&file_path).unwrap();
+        buffer.load_and_set_baseline().unwrap();
+        buffer.normalize_on_save(NormalizeOptions {
+            trim_trailing_whitespace: true,
+            ensure_final_newline: true,
+        });
+
+        let edited = "# Title\n\nNew paragraph   ";
+        buffer.update_modified_from_content(edited);
+        assert!(buffer.is_modified);
+
+        // The editor is updated from the new baseline, which matches the file
+        buffer.save_content(edited).unwrap();
+        let written = buffer.baseline_content.clone();
+        assert_eq!(written, "# Title\n\nNew paragraph\n");
+        assert_eq!(fs::read_to_string(&file_path).unwrap(), written);
+        buffer.update_modified_from_content(&written);
+        assert!(!buffer.is_modified);
+    }
+
+    #[test]
+    fn smoke_test_save_clean_content_is_unchanged() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("clean.md");
+        let clean = "# Title\n\nBody text\n";
+
+        let mut buffer = DocumentBuffer::new_untitled();
+        buffer.normalize_on_save(NormalizeOptions {
+            trim_trailing_whitespace: true,
+            ensure_final_newline: true,
+        });
+        buffer.save_as_content(&file_path, clean).unwrap();
+        assert_eq!(fs::read_to_string(&file_path).unwrap(), clean);
+
+        // The editor content still matches the baseline, so no dirty flag
+        buffer.update_modified_from_content(clean);
+        assert!(!buffer.is_modified);
+        assert_eq!(buffer.get_full_title(), "clean.md");
+    }
 }
@@ -0,0 +1,411 @@
+//! Batch conversion of Markdown files to HTML
+//!
+//! Walks a source directory, renders every Markdown file with the Marco engine
+//! and writes the result to a mirrored directory tree. Failures are collected
+//! per file so a single bad document does not abort the batch.
+use crate::logic::loaders::theme_loader::load_html_view_theme;
+use marco_core::logic::cache::parse_to_html;
+use marco_core::RenderOptions;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// File extensions treated as Markdown sources
+const MARKDOWN_EXTENSIONS: &[&str] = &["md", "marco"];
+
+/// Preview theme used to turn converted files into standalone HTML documents
+#[derive(Debug, Clone)]
+pub struct ConvertTheme {
+    /// Directory containing the HTML view themes
+    pub theme_dir: PathBuf,
+    /// Theme filename, with or without `.css` (e.g. "github")
+    pub theme_name: String,
+}
+
+/// Outcome of a [`convert_directory`] run
+#[derive(Debug, Clone, Default)]
+pub struct ConvertReport {
+    /// Output files that were written successfully
+    pub converted: Vec<PathBuf>,
+    /// Source files that could not be converted
+    pub failed: Vec<ConvertFailure>,
+    /// The requested theme, if it was replaced by the default preview theme
+    pub theme_fallback_from: Option<String>,
+}
+
+/// A single source file that failed to convert
+#[derive(Debug, Clone)]
+pub struct ConvertFailure {
+    /// Path of the source file (or directory, if it could not be listed)
+    pub source: PathBuf,
+    /// Human-readable reason for the failure
+    pub error: String,
+}
+
+impl ConvertReport {
+    /// Returns `true` if every Markdown file was converted
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+
+    fn fail(&mut self, source: PathBuf, error: String) {
+        log::warn!("Failed to convert {}: {}", source.display(), error);
+        self.failed.push(ConvertFailure { source, error });
+    }
+}
+
+/// Theme CSS loaded once per run and wrapped around every converted body
+struct StandaloneTemplate {
+    css: String,
+    theme_mode: &'static str,
+}
+
+/// Convert every Markdown file below `src` to HTML below `dst`
+///
+/// `.md` and `.marco` files are rendered to `.html` files at the same relative
+/// path; all other files are skipped. Output directories are created as needed.
+/// Symlinked directories are not followed, so links back to an ancestor cannot
+/// recurse forever. If two sources map to the same output (`a.md` and
+/// `a.marco`), the first in sorted order is converted and the other is
+/// reported as failed.
+///
+/// With a `theme`, each output is a complete HTML document with the theme CSS
+/// inlined; the light or dark variant follows `opts.theme`. The theme is
+/// loaded once up front, and a substitution by the default theme is recorded
+/// in [`ConvertReport::theme_fallback_from`]. Without a theme, each output is
+/// the bare rendered body fragment, for callers that apply their own page
+/// template.
+///
+/// # Arguments
+/// * `src` - Source directory to walk recursively
+/// * `dst` - Destination directory for the rendered HTML
+/// * `opts` - Render options applied to every file
+/// * `theme` - Preview theme for standalone documents, or `None` for fragments
+///
+/// # Returns
+/// * `Ok(ConvertReport)` - Per-file successes and failures
+/// * `Err(std::io::Error)` - If `src` cannot be read, `dst` cannot be created,
+///   or neither the requested nor the default theme can be loaded
+///
+/// # Example
+/// ```no_run
+/// use std::path::{Path, PathBuf};
+/// use marco_core::RenderOptions;
+/// use marco_shared::logic::convert::{convert_directory, ConvertTheme};
+///
+/// # fn main() -> std::io::Result<()> {
+/// let theme = ConvertTheme {
+///     theme_dir: PathBuf::from("assets/themes/html_viever"),
+///     theme_name: "github".to_string(),
+/// };
+/// let report = convert_directory(
+///     Path::new("docs"),
+///     Path::new("site"),
+///     &RenderOptions::default(),
+///     Some(&theme),
+/// )?;
+/// for failure in &report.failed {
+///     eprintln!("{}: {}", failure.source.display(), failure.error);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn convert_directory(
+    src: &Path,
+    dst: &Path,
+    opts: &RenderOptions,
+    theme: Option<&ConvertTheme>,
+) -> std::io::Result<ConvertReport> {
+    let mut report = ConvertReport::default();
+    let template = match theme {
+        Some(theme) => {
+            let loaded = load_html_view_theme(&theme.theme_dir, &theme.theme_name)?;
+            report.theme_fallback_from = loaded.fallback_from;
+            let theme_mode = if opts.theme.contains("dark") {
+                "theme-dark"
+            } else {
+                "theme-light"
+            };
+            Some(StandaloneTemplate {
+                css: loaded.css,
+                theme_mode,
+            })
+        }
+        None => None,
+    };
+
+    std::fs::create_dir_all(dst)?;
+    convert_dir_recursive(src, dst, opts, template.as_ref(), &mut report)?;
+
+    log::info!(
+        "Converted {} file(s) from {} to {} ({} failed)",
+        report.converted.len(),
+        src.display(),
+        dst.display(),
+        report.failed.len()
+    );
+    Ok(report)
+}
+
+fn convert_dir_recursive(
+    src: &Path,
+    dst: &Path,
+    opts: &RenderOptions,
+    template: Option<&StandaloneTemplate>,
+    report: &mut ConvertReport,
+) -> std::io::Result<()> {
+    let mut entries = Vec::new();
+    for entry in std::fs::read_dir(src)? {
+        match entry.and_then(|entry| Ok((entry.path(), entry.file_type()?))) {
+            Ok(entry) => entries.push(entry),
+            Err(e) => report.fail(src.to_path_buf(), format!("Failed to read entry: {}", e)),
+        }
+    }
+    // Sort entries so reports and collision winners are stable across platforms
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+    // Output path -> source that produced it, to catch `a.md` / `a.marco` clashes
+    let mut outputs: HashMap<PathBuf, PathBuf> = HashMap::new();
+
+    for (path, file_type) in entries {
+        let Some(name) = path.file_name() else {
+            continue;
+        };
+
+        // `file_type` does not follow symlinks, so linked directories are skipped
+        if file_type.is_dir() {
+            let out_dir = dst.join(name);
+            if let Err(e) = convert_dir_recursive(&path, &out_dir, opts, template, report) {
+                report.fail(path, e.to_string());
+            }
+        } else if file_type.is_symlink() && path.is_dir() {
+            log::debug!("Skipping symlinked directory {}", path.display());
+        } else if is_markdown_file(&path) {
+            let out_path = dst.join(name).with_extension("html");
+            if let Some(first) = outputs.get(&out_path) {
+                let error = format!(
+                    "Output {} is already written from {}",
+                    out_path.display(),
+                    first.display()
+                );
+                report.fail(path, error);
+                continue;
+            }
+
+            match convert_file(&path, &out_path, opts, template) {
+                Ok(()) => {
+                    outputs.insert(out_path.clone(), path);
+                    report.converted.push(out_path);
+                }
+                Err(error) => report.fail(path, error),
+            }
+        }
+    }
+    Ok(())
+}
+
+fn convert_file(
+    src: &Path,
+    dst: &Path,
+    opts: &RenderOptions,
+    template: Option<&StandaloneTemplate>,
+) -> Result<(), String> {
+    let markdown =
+        std::fs::read_to_string(src).map_err(|e| format!("Failed to read file: {}", e))?;
+    let body =
+        parse_to_html(&markdown, opts.clone()).map_err(|e| format!("Failed to render: {}", e))?;
+
+    let html = match template {
+        Some(template) => marco_core::render::wrap_preview_html_document(
+            &body,
+            &template.css,
+            template.theme_mode,
+            None,
+        ),
+        None => body,
+    };
+
+    if let Some(parent) = dst.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    std::fs::write(dst, html).map_err(|e| format!("Failed to write {}: {}", dst.display(), e))
+}
+
+fn is_markdown_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| {
+            MARKDOWN_EXTENSIONS
+                .iter()
+                .any(|known| ext.eq_ignore_ascii_case(known))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::logic::loaders::theme_loader::DEFAULT_HTML_VIEW_THEME;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn convert(src: &Path, dst: &Path) -> std::io::Result<ConvertReport> {
+        convert_directory(src, dst, &RenderOptions::default(), None)
+    }
+
+    #[test]
+    fn smoke_test_convert_mirrors_directory_tree() {
+        let src = TempDir::new().unwrap();
+        let dst = TempDir::new().unwrap();
+        fs::create_dir_all(src.path().join("guide/nested")).unwrap();
+        fs::write(src.path().join("index.md"), "# Index\n").unwrap();
+        fs::write(src.path().join("guide/intro.marco"), "# Intro\n").unwrap();
+        fs::write(src.path().join("guide/nested/deep.md"), "Deep text\n").unwrap();
+        fs::write(src.path().join("guide/notes.txt"), "not markdown").unwrap();
+
+        let report = convert(src.path(), dst.path()).unwrap();
+
+        assert!(report.is_success());
+        assert_eq!(report.converted.len(), 3);
+        assert!(dst.path().join("index.html").is_file());
+        assert!(dst.path().join("guide/intro.html").is_file());
+        assert!(dst.path().join("guide/nested/deep.html").is_file());
+        assert!(!dst.path().join("guide/notes.html").exists());
+        assert!(!dst.path().join("guide/notes.txt").exists());
+
+        let html = fs::read_to_string(dst.path().join("guide/intro.html")).unwrap();
+        assert!(html.contains("Intro"));
+    }
+
+    #[test]
+    fn smoke_test_convert_reports_failures_without_aborting() {
+        let src = TempDir::new().unwrap();
+        let dst = TempDir::new().unwrap();
+        fs::write(src.path().join("good.md"), "Hello\n").unwrap();
+        // Invalid UTF-8 cannot be read as Markdown text
+        fs::write(src.path().join("broken.md"), [0xff, 0xfe, 0xfd]).unwrap();
+
+        let report = convert(src.path(), dst.path()).unwrap();
+
+        assert!(!report.is_success());
+        assert_eq!(report.converted, vec![dst.path().join("good.html")]);
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].source, src.path().join("broken.md"));
+        assert!(!dst.path().join("broken.html").exists());
+    }
+
+    #[test]
+    fn smoke_test_convert_reports_output_collisions() {
+        let src = TempDir::new().unwrap();
+        let dst = TempDir::new().unwrap();
+        fs::write(src.path().join("page.md"), "From md\n").unwrap();
+        fs::write(src.path().join("page.marco"), "From marco\n").unwrap();
+
+        let report = convert(src.path(), dst.path()).unwrap();
+
+        // "page.marco" sorts first and wins; "page.md" is reported, not written
+        assert_eq!(report.converted, vec![dst.path().join("page.html")]);
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].source, src.path().join("page.md"));
+        let html = fs::read_to_string(dst.path().join("page.html")).unwrap();
+        assert!(html.contains("From marco"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn smoke_test_convert_does_not_follow_directory_symlinks() {
+        let src = TempDir::new().unwrap();
+        let dst = TempDir::new().unwrap();
+        fs::create_dir_all(src.path().join("docs")).unwrap();
+        fs::write(src.path().join("docs/page.md"), "Page\n").unwrap();
+        // A link back to an ancestor would recurse forever if followed
+        std::os::unix::fs::symlink(src.path(), src.path().join("docs/loop")).unwrap();
+
+        let report = convert(src.path(), dst.path()).unwrap();
+
+        assert!(report.is_success());
+        assert_eq!(report.converted, vec![dst.path().join("docs/page.html")]);
+        assert!(!dst.path().join("docs/loop").exists());
+    }
+
+    #[test]
+    fn smoke_test_convert_with_theme_writes_standalone_documents() {
+        let src = TempDir::new().unwrap();
+        let dst = TempDir::new().unwrap();
+        let themes = TempDir::new().unwrap();
+        fs::write(src.path().join("index.md"), "# Index\n").unwrap();
+        fs::write(themes.path().join("github.css"), "body { color: #123456; }").unwrap();
+
+        let theme = ConvertTheme {
+            theme_dir: themes.path().to_path_buf(),
+            theme_name: "github".to_string(),
+        };
+        let opts = RenderOptions {
+            theme: "dark".to_string(),
+            ..Default::default()
+        };
+        let report = convert_directory(src.path(), dst.path(), &opts, Some(&theme)).unwrap();
+
+        assert!(report.is_success());
+        assert!(report.theme_fallback_from.is_none());
+        let html = fs::read_to_string(dst.path().join("index.html")).unwrap();
+        assert!(html.contains("<html"));
+        assert!(html.contains("color: #123456;"));
+        assert!(html.contains("theme-dark"));
+        assert!(html.contains("Index"));
+    }
+
+    #[test]
+    fn smoke_test_convert_reports_theme_fallback() {
+        let src = TempDir::new().unwrap();
+        let dst = TempDir::new().unwrap();
+        let themes = TempDir::new().unwrap();
+        fs::write(src.path().join("index.md"), "# Index\n").unwrap();
+        fs::write(
+            themes.path().join(DEFAULT_HTML_VIEW_THEME),
+            "body { color: #abcdef; }",
+        )
+        .unwrap();
+
+        let theme = ConvertTheme {
+            theme_dir: themes.path().to_path_buf(),
+            theme_name: "missing".to_string(),
+        };
+        let report = convert_directory(
+            src.path(),
+            dst.path(),
+            &RenderOptions::default(),
+            Some(&theme),
+        )
+        .unwrap();
+
+        assert!(report.is_success());
+        assert_eq!(report.theme_fallback_from.as_deref(), Some("missing"));
+        let html = fs::read_to_string(dst.path().join("index.html")).unwrap();
+        assert!(html.contains("color: #abcdef;"));
+    }
+
+    #[test]
+    fn smoke_test_convert_without_any_theme_fails_early() {
+        let src = TempDir::new().unwrap();
+        let dst = TempDir::new().unwrap();
+        let themes = TempDir::new().unwrap();
+        fs::write(src.path().join("index.md"), "# Index\n").unwrap();
+
+        let theme = ConvertTheme {
+            theme_dir: themes.path().to_path_buf(),
+            theme_name: "missing".to_string(),
+        };
+        let out = dst.path().join("site");
+        let result = convert_directory(src.path(), &out, &RenderOptions::default(), Some(&theme));
+
+        assert!(result.is_err());
+        assert!(!out.exists());
+    }
+
+    #[test]
+    fn smoke_test_convert_missing_source_is_an_error() {
+        let dst = TempDir::new().unwrap();
+        let missing = dst.path().join("does-not-exist");
+        assert!(convert(&missing, dst.path()).is_err());
+    }
+}
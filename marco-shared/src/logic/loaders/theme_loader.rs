@@ -1,6 +1,9 @@
 //! List and label all application themes in src/assets/themes/
 use std::path::Path;
 
+/// Preview theme used when a requested HTML view theme cannot be found
+pub const DEFAULT_HTML_VIEW_THEME: &str = "marco.css";
+
 /// List all *.css HTML view themes in the given folder, with user-friendly labels
 pub fn list_html_view_themes(theme_dir: &Path) -> Vec<ThemeEntry> {
     let mut entries = vec![];
@@ -33,6 +36,129 @@ pub fn list_html_view_themes(theme_dir: &Path) -> Vec<ThemeEntry> {
     entries
 }
 
+/// Preview theme CSS resolved by [`load_html_view_theme`]
+#[derive(Debug, Clone)]
+pub struct LoadedThemeCss {
+    /// CSS content of the theme that was loaded
+    pub css: String,
+    /// Filename of the theme that was loaded (e.g. "github.css")
+    pub theme: String,
+    /// The requested theme name, if it was replaced by [`DEFAULT_HTML_VIEW_THEME`]
+    pub fallback_from: Option<String>,
+}
+
+/// Load an HTML view theme, falling back to the default theme
+///
+/// If the requested theme cannot be read, [`DEFAULT_HTML_VIEW_THEME`] is loaded
+/// instead. The substitution is logged as a warning and recorded in
+/// [`LoadedThemeCss::fallback_from`] so callers can report it.
+///
+/// # Arguments
+/// * `theme_dir` - Directory containing the HTML view themes
+/// * `theme_name` - Theme filename, with or without `.css` (e.g. "github")
+///
+/// # Returns
+/// * `Ok(LoadedThemeCss)` - The requested theme, or the default theme
+/// * `Err(std::io::Error)` - If neither theme could be read
+pub fn load_html_view_theme(theme_dir: &Path, theme_name: &str) -> std::io::Result<LoadedThemeCss> {
+    let requested = theme_css_filename(theme_name);
+    let error = match std::fs::read_to_string(theme_dir.join(&requested)) {
+        Ok(css) => {
+            return Ok(LoadedThemeCss {
+                css,
+                theme: requested,
+                fallback_from: None,
+            })
+        }
+        Err(e) => e,
+    };
+
+    let fallback = if requested == DEFAULT_HTML_VIEW_THEME {
+        Err(error)
+    } else {
+        log::warn!(
+            "Preview theme '{}' not available in {} ({}), falling back to '{}'",
+            theme_name,
+            theme_dir.display(),
+            error,
+            DEFAULT_HTML_VIEW_THEME
+        );
+        std::fs::read_to_string(theme_dir.join(DEFAULT_HTML_VIEW_THEME))
+    };
+
+    let css = fallback.inspect_err(|e| {
+        log::error!(
+            "Default preview theme '{}' not available in {}: {}",
+            DEFAULT_HTML_VIEW_THEME,
+            theme_dir.display(),
+            e
+        );
+    })?;
+
+    Ok(LoadedThemeCss {
+        css,
+        theme: DEFAULT_HTML_VIEW_THEME.to_string(),
+        fallback_from: Some(theme_name.to_string()),
+    })
+}
+
+/// Load the CSS for an HTML view theme, falling back to the default theme
+///
+/// Shorthand for [`load_html_view_theme`] when only the CSS is needed; the
+/// substitution is still logged.
+///
+/// # Returns
+/// * `Ok(String)` - CSS of the requested theme, or of the default theme
+/// * `Err(std::io::Error)` - If neither theme could be read
+pub fn load_html_view_theme_css(theme_dir: &Path, theme_name: &str) -> std::io::Result<String> {
+    load_html_view_theme(theme_dir, theme_name).map(|theme| theme.css)
+}
+
+/// Build a complete standalone HTML document with a preview theme inlined
+///
+/// The theme CSS is read from `theme_dir` and embedded in a `<style>` tag via
+/// the shared preview document wrapper, so the result opens correctly without
+/// access to the asset directory.
+///
+/// Besides the theme name, the caller passes `theme_mode` because preview
+/// themes carry both light and dark rules selected by the root class; without
+/// it every bundled document would render light.
+///
+/// # Arguments
+/// * `body` - Rendered HTML body
+/// * `theme_name` - Theme filename, with or without `.css` (e.g. "github")
+/// * `theme_dir` - Directory containing the HTML view themes
+/// * `theme_mode` - Theme class for the document root ("theme-light" or "theme-dark")
+///
+/// # Returns
+/// * `Ok(String)` - Complete HTML document
+/// * `Err(std::io::Error)` - If neither the requested theme nor
+///   [`DEFAULT_HTML_VIEW_THEME`] could be read
+pub fn bundle_standalone_html(
+    body: &str,
+    theme_name: &str,
+    theme_dir: &Path,
+    theme_mode: &str,
+) -> std::io::Result<String> {
+    let css = load_html_view_theme_css(theme_dir, theme_name)?;
+
+    Ok(marco_core::render::wrap_preview_html_document(
+        body, &css, theme_mode, None,
+    ))
+}
+
+/// Normalize a theme name to its CSS filename ("github" -> "github.css")
+///
+/// Both "github" and "github.css" are accepted wherever a preview theme is
+/// named, so every lookup goes through this helper.
+pub fn theme_css_filename(theme_name: &str) -> String {
+    if theme_name.ends_with(".css") {
+        theme_name.to_string()
+    } else {
+        format!("{}.css", theme_name)
+    }
+}
+
 /// List all *.xml style scheme files for the editor, with user-friendly labels
 pub fn list_editor_style_schemes(theme_dir: &Path) -> Vec<ThemeEntry> {
     let mut entries = vec![];
@@ -118,3 +244,95 @@ pub struct ThemeEntry {
     pub filename: String,
     pub label: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn theme_dir_with(themes: &[(&str, &str)]) -> TempDir {
+        let dir = TempDir::new().unwrap();
+        for (name, css) in themes {
+            fs::write(dir.path().join(name), css).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn smoke_test_bundle_inlines_theme_css() {
+        let dir = theme_dir_with(&[
+            ("github.css", "body { color: #123456; }"),
+            (DEFAULT_HTML_VIEW_THEME, "body { color: #abcdef; }"),
+        ]);
+
+        let html =
+            bundle_standalone_html("<p>Hello</p>", "github", dir.path(), "theme-light").unwrap();
+        assert!(html.contains("<style"));
+        assert!(html.contains("color: #123456;"));
+        assert!(!html.contains("color: #abcdef;"));
+        assert!(html.contains("<p>Hello</p>"));
+
+        // Dark mode is passed through so the theme's `.theme-dark` rules apply
+        let html =
+            bundle_standalone_html("<p>Hello</p>", "github", dir.path(), "theme-dark").unwrap();
+        assert!(html.contains("theme-dark"));
+
+        // The ".css" suffix is optional
+        let html = bundle_standalone_html("<p>Hello</p>", "github.css", dir.path(), "theme-light")
+            .unwrap();
+        assert!(html.contains("color: #123456;"));
+    }
+
+    #[test]
+    fn smoke_test_bundle_unknown_theme_falls_back_to_default() {
+        let dir = theme_dir_with(&[(DEFAULT_HTML_VIEW_THEME, "body { color: #abcdef; }")]);
+
+        let html =
+            bundle_standalone_html("<p>Hello</p>", "does-not-exist", dir.path(), "theme-light")
+                .unwrap();
+        assert!(html.contains("color: #abcdef;"));
+        assert!(html.contains("<p>Hello</p>"));
+    }
+
+    #[test]
+    fn smoke_test_load_existing_theme_without_fallback() {
+        let dir = theme_dir_with(&[
+            ("github.css", "body { color: #123456; }"),
+            (DEFAULT_HTML_VIEW_THEME, "body { color: #abcdef; }"),
+        ]);
+
+        let theme = load_html_view_theme(dir.path(), "github").unwrap();
+        assert_eq!(theme.theme, "github.css");
+        assert_eq!(theme.css, "body { color: #123456; }");
+        assert!(theme.fallback_from.is_none());
+    }
+
+    #[test]
+    fn smoke_test_load_missing_theme_reports_substitution() {
+        let dir = theme_dir_with(&[(DEFAULT_HTML_VIEW_THEME, "body { color: #abcdef; }")]);
+
+        let theme = load_html_view_theme(dir.path(), "missing.css").unwrap();
+        assert_eq!(theme.theme, DEFAULT_HTML_VIEW_THEME);
+        assert_eq!(theme.css, "body { color: #abcdef; }");
+        assert_eq!(theme.fallback_from.as_deref(), Some("missing.css"));
+
+        let css = load_html_view_theme_css(dir.path(), "missing.css").unwrap();
+        assert_eq!(css, theme.css);
+    }
+
+    #[test]
+    fn smoke_test_load_without_default_theme_is_an_error() {
+        let dir = TempDir::new().unwrap();
+        assert!(load_html_view_theme_css(dir.path(), "missing").is_err());
+        assert!(load_html_view_theme_css(dir.path(), DEFAULT_HTML_VIEW_THEME).is_err());
+    }
+
+    #[test]
+    fn smoke_test_bundle_without_any_theme_is_an_error() {
+        let dir = TempDir::new().unwrap();
+        assert!(
+            bundle_standalone_html("<p>Hello</p>", "github", dir.path(), "theme-light").is_err()
+        );
+    }
+}
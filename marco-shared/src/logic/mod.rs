@@ -1,4 +1,6 @@
+pub mod breadcrumbs;
 pub mod buffer;
+pub mod convert;
 pub mod crossplatforms;
 pub mod layoutstate;
 pub mod loaders;
@@ -692,6 +692,8 @@ pub struct EditorSettings {
     pub diagnostics_filter: Option<DiagnosticsFilterSettings>,
     /// Auto-align table columns on Tab/Enter while editing inside a table.
     pub table_auto_align: Option<bool>,
+    /// Trim trailing whitespace and ensure a final newline when saving.
+    pub normalize_on_save: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -8,6 +8,7 @@
 //! - Documentation (user guide, help files)
 
 use super::core::{find_asset_root, AssetError};
+use crate::logic::loaders::theme_loader::theme_css_filename;
 use std::path::PathBuf;
 
 /// Paths to assets shared between Marco and Polo
@@ -181,12 +182,8 @@ impl SharedPaths {
     /// ```
     pub fn preview_theme(&self, theme_name: &str) -> PathBuf {
         // Support both "github.css" and "github" formats
-        let filename = if theme_name.ends_with(".css") {
-            theme_name.to_string()
-        } else {
-            format!("{}.css", theme_name)
-        };
-        self.preview_themes_dir().join(filename)
+        self.preview_themes_dir()
+            .join(theme_css_filename(theme_name))
     }
 
     /// List all available preview themes
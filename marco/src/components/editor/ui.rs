@@ -490,11 +490,10 @@ pub fn create_editor_with_preview_and_buffer(
         }
     }
 
-    use std::fs;
-    use std::path::Path;
-    let css_path = Path::new(preview_theme_dir).join(preview_theme_filename);
-    let mut css = fs::read_to_string(&css_path)
-        .unwrap_or_else(|_| String::from("body { background: #fff; color: #222; }"));
+    let mut css = crate::theme::preview_theme_css(
+        std::path::Path::new(preview_theme_dir),
+        preview_theme_filename,
+    );
 
     // Add Marco indentation CSS to the theme CSS
     css.push('\n');
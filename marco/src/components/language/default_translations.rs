@@ -215,6 +215,8 @@ pub(crate) fn load_default_translations() -> Translations {
                 syntax_colors_description: "Enable or disable syntax-based color highlighting for Markdown.".to_string(),
                 table_auto_align_label: "Auto-Align Tables".to_string(),
                 table_auto_align_description: "Automatically realign table columns when pressing Tab, Enter, or moving the cursor outside a table.".to_string(),
+                normalize_on_save_label: "Clean Up on Save".to_string(),
+                normalize_on_save_description: "Remove trailing whitespace and end the file with a single newline when saving. Code blocks and hard line breaks are kept.".to_string(),
             },
             intelligence: SettingsIntelligenceTranslations {
                 section_intelligence: "Intelligence".to_string(),
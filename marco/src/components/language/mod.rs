@@ -313,6 +313,8 @@ pub struct SettingsEditorTranslations {
     pub syntax_colors_description: String,
     pub table_auto_align_label: String,
     pub table_auto_align_description: String,
+    pub normalize_on_save_label: String,
+    pub normalize_on_save_description: String,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -1375,6 +1377,16 @@ impl SimpleLocalizationManager {
                         &["settings", "editor", "table_auto_align_description"],
                         &fallback.settings.editor.table_auto_align_description,
                     ),
+                    normalize_on_save_label: Self::get_string(
+                        value,
+                        &["settings", "editor", "normalize_on_save_label"],
+                        &fallback.settings.editor.normalize_on_save_label,
+                    ),
+                    normalize_on_save_description: Self::get_string(
+                        value,
+                        &["settings", "editor", "normalize_on_save_description"],
+                        &fallback.settings.editor.normalize_on_save_description,
+                    ),
                 },
                 intelligence: SettingsIntelligenceTranslations {
                     section_intelligence: Self::get_string(
@@ -26,7 +26,7 @@ use crate::components::editor::footer::{refresh_footer_snapshot, wire_footer_upd
 use crate::components::editor::ui::create_editor_with_preview_and_buffer;
 use crate::components::language::{LocalizationProvider, SimpleLocalizationManager};
 use crate::components::viewer::preview_types::ViewMode;
-use crate::theme::ThemeManager;
+use crate::theme::{preview_theme_css, ThemeManager};
 use crate::ui::menu_items::files::FileDialogs;
 use crate::ui::menu_items::FileOperations;
 #[cfg(target_os = "windows")]
@@ -911,6 +911,14 @@ fn build_ui(app: &Application, initial_file: Option<String>, marco_paths: Rc<Mar
             .unwrap_or(true);
         crate::logic::tables::set_table_auto_align(table_auto_align);
 
+        // Apply save normalization setting at startup.
+        let normalize_on_save = settings_manager
+            .get_settings()
+            .editor
+            .and_then(|e| e.normalize_on_save)
+            .unwrap_or(false);
+        crate::ui::menu_items::file_operations::set_normalize_on_save(normalize_on_save);
+
         // Apply saved text direction (LTR/RTL) to the entire application at startup.
         let is_rtl = crate::logic::rtl::is_rtl_from_settings(&settings_manager);
         crate::logic::rtl::apply_text_direction(is_rtl, &window, &editor_source_view);
@@ -1355,11 +1363,9 @@ fn build_ui(app: &Application, initial_file: Option<String>, marco_paths: Rc<Mar
                             refresh_preview_weak.upgrade(),
                         ) {
                             // On preview theme change, update CSS and call refresh
-                            use std::fs;
                             let theme_manager = theme_manager.borrow();
                             let preview_theme_dir = theme_manager.preview_theme_dir.clone();
-                            let css_path = preview_theme_dir.join(&theme_filename);
-                            let css = fs::read_to_string(&css_path).unwrap_or_default();
+                            let css = preview_theme_css(&preview_theme_dir, &theme_filename);
                             *preview_css_rc.borrow_mut() = css;
                             (refresh_preview_rc.borrow())();
                         } else {
@@ -1849,10 +1855,8 @@ fn build_ui(app: &Application, initial_file: Option<String>, marco_paths: Rc<Mar
                             };
                             let export_dark = settings.theme_mode == "dark";
 
-                            let export_theme_css = {
-                                let css_path = preview_theme_dir.join(&settings.theme);
-                                std::fs::read_to_string(&css_path).unwrap_or_default()
-                            };
+                            let export_theme_css =
+                                preview_theme_css(&preview_theme_dir, &settings.theme);
                             let export_syntax_css =
                                 crate::logic::syntax_highlighter::generate_css_with_global(
                                     &settings.theme_mode,
@@ -1997,10 +2001,8 @@ fn build_ui(app: &Application, initial_file: Option<String>, marco_paths: Rc<Mar
                                 "theme-light"
                             };
 
-                            let export_theme_css = {
-                                let css_path = preview_theme_dir.join(&settings.theme);
-                                std::fs::read_to_string(&css_path).unwrap_or_default()
-                            };
+                            let export_theme_css =
+                                preview_theme_css(&preview_theme_dir, &settings.theme);
                             let export_syntax_css =
                                 crate::logic::syntax_highlighter::generate_css_with_global(
                                     &settings.theme_mode,
@@ -2213,10 +2215,7 @@ fn build_ui(app: &Application, initial_file: Option<String>, marco_paths: Rc<Mar
                         "theme-light"
                     };
 
-                    let export_theme_css = {
-                        let css_path = preview_theme_dir.join(&settings.theme);
-                        std::fs::read_to_string(&css_path).unwrap_or_default()
-                    };
+                    let export_theme_css = preview_theme_css(&preview_theme_dir, &settings.theme);
                     let export_syntax_css =
                         crate::logic::syntax_highlighter::generate_css_with_global(
                             &settings.theme_mode,
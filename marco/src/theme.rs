@@ -29,6 +29,13 @@ pub fn list_preview_themes(theme_dir: &Path) -> Vec<String> {
         .unwrap_or_default()
 }
 
+/// Loads the CSS for a preview theme, or an empty string if none is available.
+/// Falls back to the default theme (logged) if the selected one is missing.
+pub fn preview_theme_css(theme_dir: &Path, theme_name: &str) -> String {
+    marco_shared::logic::loaders::theme_loader::load_html_view_theme_css(theme_dir, theme_name)
+        .unwrap_or_default()
+}
+
 /// Determines the effective color mode (light/dark) based on settings and system.
 pub fn resolve_effective_mode(color_mode: &str) -> String {
     match color_mode.to_lowercase().as_str() {
@@ -4,13 +4,37 @@
 use crate::components::language::{DialogTranslations, MenuTranslations};
 use gtk4::{gio, glib, prelude::*};
 use log::trace;
+use marco_shared::logic::buffer::{normalize_edits, NormalizeOptions};
 use marco_shared::logic::{DocumentBuffer, RecentFiles};
 use std::cell::RefCell;
 use std::path::Path;
 use std::rc::Rc;
 use std::result::Result;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
+/// Whether saving trims trailing whitespace and ensures a final newline.
+///
+/// Loaded from `editor.normalize_on_save` in `settings.ron` at startup.
+/// Updated live when the user toggles the setting in Preferences.
+static NORMALIZE_ON_SAVE: AtomicBool = AtomicBool::new(false);
+
+/// Set the save normalization flag.  Called at startup and whenever the
+/// setting changes in the Preferences dialog.
+pub fn set_normalize_on_save(enabled: bool) {
+    NORMALIZE_ON_SAVE.store(enabled, Ordering::Relaxed);
+    log::debug!("[file_ops] normalize on save = {enabled}");
+}
+
+/// Normalization options for the next save, derived from the current setting
+fn save_normalize_options() -> NormalizeOptions {
+    let enabled = NORMALIZE_ON_SAVE.load(Ordering::Relaxed);
+    NormalizeOptions {
+        trim_trailing_whitespace: enabled,
+        ensure_final_newline: enabled,
+    }
+}
+
 // Type aliases to simplify complex callback signatures
 type OpenDialogCallback = Arc<
     dyn for<'b> Fn(
@@ -293,11 +317,7 @@ impl FileOperations {
                         )
                         .await?;
                         if let Some(save_path) = file_path {
-                            let content = self.get_editor_content(editor_buffer);
-                            self.buffer
-                                .borrow_mut()
-                                .save_as_content(&save_path, &content)?;
-                            self.buffer.borrow_mut().set_baseline(&content);
+                            self.save_editor_content(editor_buffer, Some(&save_path))?;
                             self.add_recent_file(&save_path);
                         } else {
                             // User cancelled Save As dialog, cancel the entire open operation
@@ -394,10 +414,7 @@ impl FileOperations {
         if buffer.get_file_path().is_some() {
             drop(buffer); // Release borrow before calling save_content
 
-            let content = self.get_editor_content(editor_buffer);
-            self.buffer.borrow_mut().save_content(&content)?;
-            // Update baseline after successful save
-            self.buffer.borrow_mut().set_baseline(&content);
+            self.save_editor_content(editor_buffer, None)?;
 
             trace!("audit: saved document to existing path");
             eprintln!("[FileOps] Saved document");
@@ -514,9 +531,7 @@ impl FileOperations {
                         )
                         .await?;
                         if let Some(path) = file_path {
-                            let content = self.get_editor_content(editor_buffer);
-                            self.buffer.borrow_mut().save_as_content(&path, &content)?;
-                            self.buffer.borrow_mut().set_baseline(&content);
+                            self.save_editor_content(editor_buffer, Some(&path))?;
                             self.add_recent_file(&path);
                         } else {
                             // User cancelled Save As dialog, cancel the entire open operation
@@ -605,9 +620,7 @@ impl FileOperations {
                         )
                         .await?;
                         if let Some(path) = file_path {
-                            let content = self.get_editor_content(editor_buffer);
-                            self.buffer.borrow_mut().save_as_content(&path, &content)?;
-                            self.buffer.borrow_mut().set_baseline(&content);
+                            self.save_editor_content(editor_buffer, Some(&path))?;
                             self.add_recent_file(&path);
                         } else {
                             // User cancelled Save As dialog, cancel the new document operation
@@ -667,13 +680,7 @@ impl FileOperations {
         )
         .await?;
         if let Some(path) = file_path {
-            let start_iter = editor_buffer.start_iter();
-            let end_iter = editor_buffer.end_iter();
-            let content = editor_buffer
-                .text(&start_iter, &end_iter, false)
-                .to_string();
-            self.buffer.borrow_mut().save_as_content(&path, &content)?;
-            self.buffer.borrow_mut().set_baseline(&content);
+            self.save_editor_content(editor_buffer, Some(&path))?;
             self.add_recent_file(&path);
             eprintln!("[FileOps] Saved file: {}", path.display());
         }
@@ -739,12 +746,7 @@ impl FileOperations {
                         )
                         .await?;
                         if let Some(path) = file_path {
-                            let start_iter = editor_buffer.start_iter();
-                            let end_iter = editor_buffer.end_iter();
-                            let content = editor_buffer
-                                .text(&start_iter, &end_iter, false)
-                                .to_string();
-                            self.buffer.borrow_mut().save_as_content(&path, &content)?;
+                            self.save_editor_content(editor_buffer, Some(&path))?;
                             self.add_recent_file(&path);
                             app.quit();
                         }
@@ -794,6 +796,49 @@ impl FileOperations {
         Ok(())
     }
 
+    /// Saves the editor content to `path` (Save As) or the current file
+    ///
+    /// With save normalization enabled, the text written to disk (the new
+    /// baseline) may differ from the editor content. Only the changed ranges
+    /// are then edited in the editor, as a single user action, so undo history,
+    /// marks and the cursor are kept and the document is not left modified.
+    fn save_editor_content(
+        &self,
+        editor_buffer: &gtk4::TextBuffer,
+        path: Option<&Path>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let content = self.get_editor_content(editor_buffer);
+        let saved = {
+            let mut buffer = self.buffer.borrow_mut();
+            buffer.normalize_on_save(save_normalize_options());
+            match path {
+                Some(path) => buffer.save_as_content(path, &content)?,
+                None => buffer.save_content(&content)?,
+            }
+            buffer.baseline_content.clone()
+        };
+
+        if saved != content {
+            *self.programmatic_buffer_update.borrow_mut() = true;
+            editor_buffer.begin_user_action();
+            for edit in normalize_edits(&content, &saved) {
+                let mut start = editor_buffer.iter_at_offset(edit.start as i32);
+                let mut end = editor_buffer.iter_at_offset(edit.end as i32);
+                editor_buffer.delete(&mut start, &mut end);
+                if !edit.text.is_empty() {
+                    editor_buffer.insert(&mut start, &edit.text);
+                }
+            }
+            editor_buffer.end_user_action();
+            *self.programmatic_buffer_update.borrow_mut() = false;
+
+            if self.get_editor_content(editor_buffer) != saved {
+                log::warn!("Editor content differs from the normalized file after save");
+            }
+        }
+        Ok(())
+    }
+
     /// Gets the current content from the editor buffer
     fn get_editor_content(&self, editor_buffer: &gtk4::TextBuffer) -> String {
         let start_iter = editor_buffer.start_iter();
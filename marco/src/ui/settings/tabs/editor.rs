@@ -602,6 +602,54 @@ pub fn build_editor_tab(
     );
     container.append(&table_auto_align_row);
 
+    // Clean Up on Save (Toggle)
+    let normalize_on_save_switch = Switch::new();
+    normalize_on_save_switch.add_css_class("marco-switch");
+
+    let current_normalize_on_save = if let Some(ref settings_manager) = settings_manager_opt {
+        settings_manager
+            .get_settings()
+            .editor
+            .and_then(|e| e.normalize_on_save)
+            .unwrap_or(false)
+    } else {
+        false
+    };
+    normalize_on_save_switch.set_active(current_normalize_on_save);
+
+    if let Some(settings_manager_clone) = settings_manager_opt.clone() {
+        normalize_on_save_switch.connect_state_set(move |_switch, state| {
+            debug!("Normalize on save changed to: {}", state);
+
+            if let Err(e) = settings_manager_clone.update_settings(|settings| {
+                if settings.editor.is_none() {
+                    settings.editor = Some(EditorSettings::default());
+                }
+                if let Some(ref mut editor) = settings.editor {
+                    editor.normalize_on_save = Some(state);
+                }
+            }) {
+                error!("Failed to save normalize on save setting: {}", e);
+                return glib::Propagation::Proceed;
+            }
+
+            crate::ui::menu_items::file_operations::set_normalize_on_save(state);
+
+            glib::Propagation::Proceed
+        });
+    }
+
+    let normalize_on_save_row = add_setting_row_i18n(
+        i18n,
+        &translations.normalize_on_save_label,
+        &translations.normalize_on_save_description,
+        Rc::new(|t: &Translations| t.settings.editor.normalize_on_save_label.clone()),
+        Rc::new(|t: &Translations| t.settings.editor.normalize_on_save_description.clone()),
+        &normalize_on_save_switch,
+        false,
+    );
+    container.append(&normalize_on_save_row);
+
     // Show Line Numbers (Toggle)
     let line_numbers_switch = Switch::new();
     line_numbers_switch.add_css_class("marco-switch");
@@ -54,8 +54,14 @@ pub fn load_theme_css_from_path(theme: &str, asset_root: &std::path::Path) -> St
 
     match std::fs::read_to_string(&theme_path) {
         Ok(css) => css,
-        Err(_) => {
+        Err(e) => {
             // Fallback to minimal styling if theme not found
+            log::warn!(
+                "Preview theme '{}' not available at {} ({}), using built-in fallback CSS",
+                theme,
+                theme_path.display(),
+                e
+            );
             String::from(
                 r#"
                 body {